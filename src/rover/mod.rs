@@ -3,25 +3,47 @@ use std::cell::RefCell;
 use std::cmp::min;
 use std::ffi::OsStr;
 use std::fs::{self, DirEntry};
-use std::io::{self, Stdout, StdoutLock, Write, stdout};
+use std::io::{self, BufRead, BufReader, Read, Stdout, StdoutLock, Write, stdout};
+use std::collections::{BTreeMap, BTreeSet};
 use std::ops::Deref;
-use std::path::{Path, PathBuf};
+use std::path::{Component as PathComponent, Path, PathBuf};
 use std::rc::Rc;
+use std::sync::mpsc;
+use std::sync::LazyLock;
+use std::time::Duration;
 use std::usize;
 
 use crossterm::cursor::{self, MoveTo, MoveToNextLine};
 use crossterm::event::{
-    DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, KeyModifiers, read,
+    DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, KeyModifiers, poll,
+    read,
 };
+use crossterm::style::{Color, ResetColor, SetBackgroundColor, SetForegroundColor};
 use crossterm::terminal::{
     BeginSynchronizedUpdate, Clear, ClearType, DisableLineWrap, EnableLineWrap,
     EndSynchronizedUpdate, EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode,
     enable_raw_mode,
 };
 use crossterm::{QueueableCommand, terminal};
+use image::imageops::FilterType;
+use notify::{Event as FsEvent, RecommendedWatcher, RecursiveMode, Watcher};
+use syntect::easy::HighlightLines;
+use syntect::highlighting::ThemeSet;
+use syntect::parsing::SyntaxSet;
 
 use crate::rover;
 
+// Loaded once and shared by every syntax-highlighted preview.
+static SYNTAX_SET: LazyLock<SyntaxSet> = LazyLock::new(SyntaxSet::load_defaults_newlines);
+static THEME_SET: LazyLock<ThemeSet> = LazyLock::new(ThemeSet::load_defaults);
+
+const IMAGE_EXTENSIONS: &[&str] = &[
+    "png", "jpg", "jpeg", "gif", "bmp", "ico", "tiff", "webp",
+];
+
+// How long we let crossterm block between polls before checking the fs watcher channel.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
 #[derive(Default, Clone, Copy)]
 pub struct Rect {
     x: usize,
@@ -39,6 +61,17 @@ impl Rect {
         self.w = w;
         self.h = h;
     }
+
+    // Splits into a left and right column, left getting the extra cell on an odd width.
+    fn split_cols(&self) -> (Rect, Rect) {
+        let left_w = self.w - self.w / 2;
+        let right_w = self.w - left_w;
+
+        (
+            Rect::new(self.x, self.y, left_w, self.h),
+            Rect::new(self.x + left_w, self.y, right_w, self.h),
+        )
+    }
 }
 
 impl Component for (bool, DirEntry) {
@@ -53,7 +86,24 @@ pub struct DirScraper {
     current_path: Option<PathBuf>,
     rover: Rover<ListEntry>,
     mode: Mode,
+    // Query typed so far while in `Mode::Command`, e.g. "filter foo" or "search-next".
+    command_buffer: String,
+    last_query: String,
+    // The argument of the last `filter` command, reapplied after a refresh so an fs
+    // event doesn't silently drop an active filter. Empty means no filter.
+    last_filter: String,
     terminal_dimens: Rect,
+    // Kept alive so the watch keeps firing; dropping it stops the subscription.
+    watcher: RecommendedWatcher,
+    fs_events: mpsc::Receiver<notify::Result<FsEvent>>,
+    bookmarks: Bookmarks,
+    // Last error from a flow-mode or command-mode action, shown on the status line
+    // instead of panicking.
+    last_error: Option<String>,
+    // The most recently built preview, plus the path it was built for; rebuilt only
+    // when the selected entry's path actually changes, not on every loop tick.
+    cached_preview: Preview,
+    cached_preview_path: Option<PathBuf>,
 }
 
 impl DirScraper {
@@ -68,15 +118,46 @@ impl DirScraper {
         rover.reset(entries.unwrap());
         rover.set_selected(0);
 
+        let (watcher, fs_events) =
+            Self::watch(&path).map_err(io::Error::other)?;
+
         Ok(DirScraper {
             current_path: Some(path),
             should_exit: false,
             rover: rover,
             mode: Mode::Flow,
+            command_buffer: String::new(),
+            last_query: String::new(),
+            last_filter: String::new(),
             terminal_dimens: dimens,
+            watcher,
+            fs_events,
+            bookmarks: Bookmarks::load(),
+            last_error: None,
+            cached_preview: Preview::None,
+            cached_preview_path: None,
         })
     }
 
+    // Stashes a failed action's message for the status line; clears it on success.
+    fn report(&mut self, result: Result<(), String>) {
+        self.last_error = result.err();
+    }
+
+    // Subscribes to non-recursive fs events for `path`, forwarding them into a channel
+    // so the event loop can poll it alongside terminal input.
+    fn watch(
+        path: &Path,
+    ) -> notify::Result<(RecommendedWatcher, mpsc::Receiver<notify::Result<FsEvent>>)> {
+        let (tx, rx) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(move |res| {
+            let _ = tx.send(res);
+        })?;
+        watcher.watch(path, RecursiveMode::NonRecursive)?;
+
+        Ok((watcher, rx))
+    }
+
     pub fn run(&mut self, stdout: &mut StdoutLock) -> std::io::Result<()> {
         stdout
             .queue(EnterAlternateScreen)?
@@ -89,44 +170,127 @@ impl DirScraper {
         let mut renderer = ListRenderer::new(self.terminal_dimens, stdout);
 
         loop {
-            match read().unwrap() {
-                Event::Key(event) if event.kind == KeyEventKind::Press => match event.code {
-                    KeyCode::Char(c) => {
-                        if event.modifiers.contains(KeyModifiers::CONTROL) {
-                            match c {
-                                'q' | 'Q' => self.should_exit = true,
-                                'c' => self.mode = Mode::Command,
-                                'f' => self.mode = Mode::Flow,
-                                'k' => self.execute_entry().unwrap(),
-                                _ => {}
-                            }
+            if poll(POLL_INTERVAL).unwrap() {
+                match read().unwrap() {
+                    Event::Key(event) if event.kind == KeyEventKind::Press => {
+                        if self.mode == Mode::Command {
+                            self.handle_command_key(event.code, event.modifiers);
+                        } else if self.mode == Mode::BookmarkSet {
+                            self.handle_bookmark_set_key(event.code);
+                        } else if self.mode == Mode::BookmarkJump {
+                            let result = self.handle_bookmark_jump_key(event.code);
+                            self.report(result);
+                        } else if self.mode == Mode::ConfirmDelete {
+                            self.handle_confirm_delete_key(event.code);
                         } else {
-                            match c.to_lowercase().next().unwrap() {
-                                'j' => self.rover.shift(Direction::Down),
-                                'k' => self.rover.shift(Direction::Up),
+                            match event.code {
+                                KeyCode::Char(c) => {
+                                    if event.modifiers.contains(KeyModifiers::CONTROL) {
+                                        match c {
+                                            'q' | 'Q' => self.should_exit = true,
+                                            'c' => self.mode = Mode::Command,
+                                            'f' => self.mode = Mode::Flow,
+                                            'k' => {
+                                                let result = self.execute_entry();
+                                                self.report(result);
+                                            }
+                                            'a' => self.rover.invert_marks(),
+                                            'u' => self.rover.clear_marks(),
+                                            'b' => self.mode = Mode::BookmarkSet,
+                                            'g' => self.mode = Mode::BookmarkJump,
+                                            'd' => self.mode = Mode::ConfirmDelete,
+                                            _ => {}
+                                        }
+                                    } else {
+                                        match c.to_lowercase().next().unwrap() {
+                                            'j' => self.rover.shift(Direction::Down),
+                                            'k' => self.rover.shift(Direction::Up),
+                                            ' ' => self.rover.toggle_mark(),
+                                            _ => {}
+                                        }
+                                    }
+                                }
+                                KeyCode::Up => self.rover.shift(Direction::Up),
+                                KeyCode::Down => self.rover.shift(Direction::Down),
+                                KeyCode::PageUp => self.rover.page(Direction::Up),
+                                KeyCode::PageDown => self.rover.page(Direction::Down),
+                                KeyCode::Home => self.rover.jump_to_start(),
+                                KeyCode::End => self.rover.jump_to_end(),
+                                KeyCode::Enter => {
+                                    let result = self.execute_entry();
+                                    self.report(result);
+                                }
+                                KeyCode::Esc => {
+                                    let result = self.to_parent_entry();
+                                    self.report(result);
+                                }
                                 _ => {}
                             }
                         }
                     }
-                    KeyCode::Up => self.rover.shift(Direction::Up),
-                    KeyCode::Down => self.rover.shift(Direction::Down),
-                    KeyCode::Enter => self.execute_entry().unwrap(),
-                    KeyCode::Esc => self.to_parent_entry().unwrap(),
+                    Event::Resize(w, h) => {
+                        self.terminal_dimens.resize(w as usize, h as usize);
+                        renderer.resize(w as usize, h as usize);
+                        self.rover.resize(w as usize, h as usize);
+                    }
                     _ => {}
-                },
-                Event::Resize(w, h) => {
-                    self.terminal_dimens.resize(w as usize, h as usize);
-                    renderer.resize(w as usize, h as usize);
-                    // TODO: reset rover.height
                 }
-                _ => {}
+            }
+
+            // Debounce: a single fs event means a refresh is due; drain whatever else
+            // piled up in the channel so a burst of writes only triggers one re-read.
+            if self.fs_events.try_recv().is_ok() {
+                while self.fs_events.try_recv().is_ok() {}
+                self.refresh();
             }
 
             // if let Some(selected) = self.rover.selected_mut() {
             //     selected.is_selected = true;
             // }
 
-            self.rover.render(&mut renderer);
+            let (_, preview_bounds) = self.terminal_dimens.split_cols();
+
+            if self.mode == Mode::BookmarkJump {
+                let mut popup = Rover::new(self.terminal_dimens.h);
+                popup.reset(self.bookmarks.rows());
+                if popup.len() > 0 {
+                    popup.set_selected(0);
+                }
+                popup.render(&mut renderer, &Preview::None);
+            } else {
+                let selected_path = self.rover.selected_ref().map(|e| e.to_path_buf());
+
+                if selected_path != self.cached_preview_path {
+                    self.cached_preview = match self.rover.selected_ref() {
+                        Some(entry) => self.preview_for(entry, preview_bounds.w, preview_bounds.h),
+                        None => Preview::None,
+                    };
+                    self.cached_preview_path = selected_path;
+                }
+
+                self.rover.render(&mut renderer, &self.cached_preview);
+            }
+
+            let status = match self.mode {
+                Mode::Command => Some(format!(":{}", self.command_buffer)),
+                Mode::ConfirmDelete => Some(format!(
+                    "Delete {} item(s)? [y/N]",
+                    self.rover.marked_or_selected().len()
+                )),
+                _ => self.last_error.as_ref().map(|e| format!("! {}", e)),
+            };
+
+            if let Some(status) = status {
+                let status_row = self.terminal_dimens.h.saturating_sub(1) as u16;
+
+                renderer
+                    .stdout()
+                    .queue(MoveTo(0, status_row))
+                    .unwrap()
+                    .write_all(status.as_bytes())
+                    .unwrap();
+                renderer.stdout().flush().unwrap();
+            }
 
             // if let Some(selected) = self.rover.selected_mut() {
             //     selected.is_selected = false;
@@ -223,48 +387,525 @@ impl DirScraper {
         let entries = Self::read_dir(&selected_path);
 
         self.current_path = Some(selected_path.to_path_buf());
+
+        let (watcher, fs_events) = Self::watch(selected_path)
+            .map_err(|e| format!("Failed to watch '{}': {}", selected_path.display(), e))?;
+        self.watcher = watcher;
+        self.fs_events = fs_events;
+
         self.rover.reset(entries?);
         self.rover.set_selected(0);
+        // A filter query is scoped to the directory it was typed in; don't let it
+        // reappear (silently, via a later fs-triggered refresh) in wherever we land next.
+        self.last_filter.clear();
 
         Ok(())
     }
 
-    fn execute_entry(&mut self) -> Result<(), String> {
-        let selected =  match self.rover.selected_ref() {
-            Some(r) => r,
-            None => return Ok(()),
+    // Re-reads the current directory in place, keeping the pivot on the same entry
+    // (by path) when it still exists, falling back to a clamped index otherwise. Marks
+    // are re-located by path too, rather than dropped, since an unrelated fs event (e.g.
+    // another process writing to the directory) shouldn't silently wipe a batch selection,
+    // and an active filter is recomputed against the fresh entries rather than cleared.
+    fn refresh(&mut self) {
+        let Some(current_path) = self.current_path.clone() else {
+            return;
+        };
+
+        let Ok(entries) = Self::read_dir(&current_path) else {
+            return;
         };
 
-        let kind = selected.kind();
+        let selected = self.rover.selected_ref().map(|e| e.to_path_buf());
+        let marked_paths: Vec<PathBuf> = self
+            .rover
+            .ctx
+            .marked
+            .iter()
+            .filter_map(|&i| self.rover.components.as_ref()?.get(i))
+            .map(|e| e.to_path_buf())
+            .collect();
+
+        let len = entries.len();
+
+        self.rover.components = Some(entries);
+
+        let components = self.rover.components.as_ref().unwrap();
+
+        let idx = selected
+            .and_then(|p| components.iter().position(|e| **e == *p))
+            .unwrap_or(0)
+            .min(len.saturating_sub(1));
 
+        self.rover.ctx.marked = marked_paths
+            .iter()
+            .filter_map(|p| components.iter().position(|e| **e == **p))
+            .collect();
+
+        self.rover.set_selected(idx);
+        self.rover.set_filter(&self.last_filter);
+
+        // The selected path may be unchanged, but its contents might not be (that's the
+        // whole reason `refresh` was triggered) - force the preview to rebuild.
+        self.cached_preview_path = None;
+    }
+
+    fn preview_for(&self, entry: &ListEntry, preview_w: usize, preview_h: usize) -> Preview {
+        match entry.kind() {
+            ListEntryKind::Dir | ListEntryKind::Parent => match fs::read_dir(&**entry) {
+                Ok(rd) => Preview::Dir(
+                    rd.filter_map(|e| e.ok())
+                        .map(|e| e.file_name().to_string_lossy().into_owned())
+                        .collect(),
+                ),
+                Err(e) => Preview::Dir(vec![format!("<{}>", e)]),
+            },
+            ListEntryKind::File => Self::preview_file(entry, preview_w, preview_h),
+        }
+    }
+
+    fn preview_file(entry: &ListEntry, preview_w: usize, preview_h: usize) -> Preview {
+        let ext = entry
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|e| e.to_lowercase())
+            .unwrap_or_default();
+
+        if IMAGE_EXTENSIONS.contains(&ext.as_str()) {
+            return match Self::preview_image(entry, preview_w, preview_h) {
+                Ok(preview) => preview,
+                Err(e) => Preview::File(vec![format!("<{}>", e)]),
+            };
+        }
+
+        if let Some(lines) = Self::preview_highlighted(entry, &ext) {
+            return Preview::Highlighted(lines);
+        }
+
+        match Self::read_preview_lines(entry) {
+            Ok(lines) => Preview::File(lines),
+            Err(_) => Preview::File(vec!["<binary or unreadable file>".to_string()]),
+        }
+    }
+
+    // Reads at most `PREVIEW_LINES` lines through a buffered reader, so previewing a
+    // huge file stays instant instead of slurping the whole thing into memory first.
+    fn read_preview_lines(entry: &ListEntry) -> io::Result<Vec<String>> {
+        let file = fs::File::open(&**entry)?;
+        BufReader::new(file.take(PREVIEW_BYTES))
+            .lines()
+            .take(PREVIEW_LINES)
+            .collect()
+    }
+
+    // Highlights the first screenful of a source file via syntect, translating each
+    // line into colored spans. Returns `None` when the extension has no known syntax.
+    // Reads the same bounded window as `read_preview_lines` for the same reason.
+    fn preview_highlighted(entry: &ListEntry, ext: &str) -> Option<Vec<Vec<StyledSpan>>> {
+        let syntax = SYNTAX_SET.find_syntax_by_extension(ext)?;
+        let file = fs::File::open(&**entry).ok()?;
+        let theme = &THEME_SET.themes["base16-ocean.dark"];
+        let mut highlighter = HighlightLines::new(syntax, theme);
+
+        let lines = BufReader::new(file.take(PREVIEW_BYTES))
+            .lines()
+            .take(PREVIEW_LINES)
+            .map_while(Result::ok)
+            .map(|mut line| {
+                // syntect wants the trailing newline to track multi-line syntax state correctly.
+                line.push('\n');
+
+                highlighter
+                    .highlight_line(&line, &SYNTAX_SET)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|(style, text)| StyledSpan {
+                        fg: (style.foreground.r, style.foreground.g, style.foreground.b),
+                        text: text.trim_end_matches(['\n', '\r']).to_string(),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Some(lines)
+    }
+
+    // Downsamples the image to one cell per `(preview_w, preview_h)` slot, pairing each
+    // cell's top/bottom source pixels into the fg/bg of an upper-half-block glyph.
+    fn preview_image(entry: &ListEntry, preview_w: usize, preview_h: usize) -> Result<Preview, String> {
+        if preview_w == 0 || preview_h == 0 {
+            return Ok(Preview::Image(vec![]));
+        }
+
+        let img = image::open(&**entry).map_err(|e| e.to_string())?;
+        let resized = img
+            .resize_exact(preview_w as u32, (preview_h * 2) as u32, FilterType::Triangle)
+            .to_rgb8();
+
+        let mut rows = Vec::with_capacity(preview_h);
+
+        for row in 0..preview_h {
+            let mut cells = Vec::with_capacity(preview_w);
+
+            for col in 0..preview_w {
+                let top = resized.get_pixel(col as u32, (row * 2) as u32);
+                let bottom = resized.get_pixel(col as u32, (row * 2 + 1) as u32);
+
+                cells.push(ImageCell {
+                    fg: (top[0], top[1], top[2]),
+                    bg: (bottom[0], bottom[1], bottom[2]),
+                });
+            }
+
+            rows.push(cells);
+        }
+
+        Ok(Preview::Image(rows))
+    }
+
+    fn execute_entry(&mut self) -> Result<(), String> {
         // I surrender to borrowing rules by cloning this bitch
-        let selected = selected.to_path_buf();
-
-        match kind {
-            // let prev_pivot = self.pivot;
-            // self.push_undo_pivot(prev_pivot);
-            ListEntryKind::Dir | ListEntryKind::Parent => self.goto(&selected)?,
-            ListEntryKind::File => {
-                opener::open(selected.display().to_string()).map_err(|e| {
-                    format!("Error opening the file '{}': {}", selected.display(), e)
-                })?;
+        let targets: Vec<(ListEntryKind, PathBuf)> = self
+            .rover
+            .marked_or_selected()
+            .into_iter()
+            .map(|e| (e.kind(), e.to_path_buf()))
+            .collect();
+
+        // Plain navigation: nothing marked, selection is a single directory.
+        if let [(ListEntryKind::Dir | ListEntryKind::Parent, path)] = targets.as_slice() {
+            return self.goto(path);
+        }
+
+        // let prev_pivot = self.pivot;
+        // self.push_undo_pivot(prev_pivot);
+        for (_, path) in targets {
+            opener::open(path.display().to_string())
+                .map_err(|e| format!("Error opening '{}': {}", path.display(), e))?;
+        }
+
+        Ok(())
+    }
+
+    // Builds up `command_buffer` while in `Mode::Command`; Enter dispatches it as a command.
+    fn handle_command_key(&mut self, code: KeyCode, modifiers: KeyModifiers) {
+        match code {
+            KeyCode::Char('f') | KeyCode::Char('F') if modifiers.contains(KeyModifiers::CONTROL) => {
+                self.command_buffer.clear();
+                self.mode = Mode::Flow;
+            }
+            KeyCode::Char('q') | KeyCode::Char('Q') if modifiers.contains(KeyModifiers::CONTROL) => {
+                self.should_exit = true;
+            }
+            KeyCode::Char(c) => self.command_buffer.push(c),
+            KeyCode::Backspace => {
+                self.command_buffer.pop();
+            }
+            KeyCode::Enter => self.run_command(),
+            KeyCode::Esc => {
+                self.command_buffer.clear();
+                self.mode = Mode::Flow;
+            }
+            _ => {}
+        }
+    }
+
+    // Dispatches the typed command: `filter <query>`, `search <query>`, `search-next`,
+    // `search-prev`, `rename <name>`, `mkdir <name>`, `touch <name>`. Matching is
+    // case-insensitive against the entry's file name.
+    fn run_command(&mut self) {
+        let cmd = self.command_buffer.trim().to_string();
+        let mut parts = cmd.splitn(2, ' ');
+        let verb = parts.next().unwrap_or("");
+        let arg = parts.next().unwrap_or("").trim();
+
+        let result = match verb {
+            "filter" => {
+                self.last_filter = arg.to_string();
+                self.rover.set_filter(arg);
+                Ok(())
+            }
+            "search" => {
+                self.last_query = arg.to_lowercase();
+                self.rover.jump_to_match(&self.last_query, Direction::Down);
+                Ok(())
+            }
+            "search-next" => {
+                self.rover.jump_to_match(&self.last_query, Direction::Down);
+                Ok(())
+            }
+            "search-prev" => {
+                self.rover.jump_to_match(&self.last_query, Direction::Up);
+                Ok(())
+            }
+            "rename" => self.rename_selected(arg),
+            "mkdir" => self.create_entry(arg, true),
+            "touch" => self.create_entry(arg, false),
+            _ => Ok(()),
+        };
+
+        self.report(result);
+
+        self.command_buffer.clear();
+        self.mode = Mode::Flow;
+    }
+
+    // 'y'/'Y' confirms; anything else cancels without touching the filesystem.
+    fn handle_confirm_delete_key(&mut self, code: KeyCode) {
+        if let KeyCode::Char('y') | KeyCode::Char('Y') = code {
+            let result = self.delete_selected();
+            self.report(result);
+        }
+
+        self.mode = Mode::Flow;
+    }
+
+    // Sends the marked (or selected) entries to the OS trash rather than unlinking them.
+    fn delete_selected(&mut self) -> Result<(), String> {
+        let targets: Vec<PathBuf> = self
+            .rover
+            .marked_or_selected()
+            .into_iter()
+            .filter(|e| !matches!(e.kind(), ListEntryKind::Parent))
+            .map(|e| e.to_path_buf())
+            .collect();
+
+        if targets.is_empty() {
+            return Ok(());
+        }
+
+        trash::delete_all(&targets).map_err(|e| format!("Failed to trash: {}", e))?;
+
+        self.rover.clear_marks();
+        self.refresh();
+
+        Ok(())
+    }
+
+    // A name that isn't a single normal path component (e.g. it contains a separator,
+    // or is `..`) would let rename/create escape `current_path` instead of just acting
+    // on an entry in place.
+    fn is_single_component(name: &str) -> bool {
+        let mut components = Path::new(name).components();
+        matches!(components.next(), Some(PathComponent::Normal(_))) && components.next().is_none()
+    }
+
+    fn rename_selected(&mut self, new_name: &str) -> Result<(), String> {
+        if new_name.is_empty() {
+            return Err("Usage: rename <new name>".to_string());
+        }
+
+        if !Self::is_single_component(new_name) {
+            return Err(format!("Invalid name '{new_name}'."));
+        }
+
+        let Some(entry) = self.rover.selected_ref() else {
+            return Err("Nothing selected.".to_string());
+        };
+
+        if matches!(entry.kind(), ListEntryKind::Parent) {
+            return Err("Cannot rename '..'.".to_string());
+        }
+
+        let old_path = entry.to_path_buf();
+        let new_path = old_path.with_file_name(new_name);
+
+        fs::rename(&old_path, &new_path)
+            .map_err(|e| format!("Failed to rename '{}': {}", old_path.display(), e))?;
+
+        self.refresh();
+
+        Ok(())
+    }
+
+    // Creates a new directory or empty file inside `current_path`.
+    fn create_entry(&mut self, name: &str, is_dir: bool) -> Result<(), String> {
+        if name.is_empty() {
+            return Err(format!(
+                "Usage: {} <name>",
+                if is_dir { "mkdir" } else { "touch" }
+            ));
+        }
+
+        if !Self::is_single_component(name) {
+            return Err(format!("Invalid name '{name}'."));
+        }
+
+        let Some(current_path) = self.current_path.as_ref() else {
+            return Err("No current directory.".to_string());
+        };
+
+        let target = current_path.join(name);
+
+        if is_dir {
+            fs::create_dir(&target)
+        } else {
+            fs::File::create(&target).map(|_| ())
+        }
+        .map_err(|e| format!("Failed to create '{}': {}", target.display(), e))?;
+
+        self.refresh();
+
+        Ok(())
+    }
+
+    // Next char typed while in `Mode::BookmarkSet` becomes the label for `current_path`.
+    fn handle_bookmark_set_key(&mut self, code: KeyCode) {
+        match code {
+            KeyCode::Char(c) => {
+                if let Some(path) = self.current_path.clone() {
+                    self.bookmarks.set(c, path);
+                }
+                self.mode = Mode::Flow;
+            }
+            KeyCode::Esc => self.mode = Mode::Flow,
+            _ => {}
+        }
+    }
+
+    // Next char typed while in `Mode::BookmarkJump` looks up a bookmark and jumps to it.
+    fn handle_bookmark_jump_key(&mut self, code: KeyCode) -> Result<(), String> {
+        match code {
+            KeyCode::Char(c) => {
+                self.mode = Mode::Flow;
+
+                if let Some(path) = self.bookmarks.get(c) {
+                    self.goto(&path)?;
+                }
             }
-            // ListEntryKind::Parent => todo!(),
+            KeyCode::Esc => self.mode = Mode::Flow,
+            _ => {}
         }
 
         Ok(())
     }
 }
 
+// Persists `label -> PathBuf` bookmarks to a plain `label\tpath` file under the user's
+// config dir, so they survive restarts across sessions.
+struct Bookmarks {
+    path: PathBuf,
+    marks: BTreeMap<char, PathBuf>,
+}
+
+impl Bookmarks {
+    fn load() -> Self {
+        let path = Self::config_path();
+        let marks = fs::read_to_string(&path)
+            .map(|s| Self::parse(&s))
+            .unwrap_or_default();
+
+        Bookmarks { path, marks }
+    }
+
+    fn config_path() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(std::env::temp_dir)
+            .join("rover")
+            .join("bookmarks")
+    }
+
+    fn parse(contents: &str) -> BTreeMap<char, PathBuf> {
+        contents
+            .lines()
+            .filter_map(|line| {
+                let (label, path) = line.split_once('\t')?;
+                Some((label.chars().next()?, PathBuf::from(path)))
+            })
+            .collect()
+    }
+
+    fn get(&self, label: char) -> Option<PathBuf> {
+        self.marks.get(&label).cloned()
+    }
+
+    fn set(&mut self, label: char, path: PathBuf) {
+        self.marks.insert(label, path);
+        self.save();
+    }
+
+    fn rows(&self) -> Vec<BookmarkRow> {
+        self.marks
+            .iter()
+            .map(|(&label, path)| BookmarkRow { label, path: path.clone() })
+            .collect()
+    }
+
+    // Best-effort: a failed save just means the bookmark doesn't survive a restart.
+    fn save(&self) {
+        if let Some(parent) = self.path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+
+        let contents: String = self
+            .marks
+            .iter()
+            .map(|(label, path)| format!("{}\t{}\n", label, path.display()))
+            .collect();
+
+        let _ = fs::write(&self.path, contents);
+    }
+}
+
+// One row of the bookmark jump popup.
+struct BookmarkRow {
+    label: char,
+    path: PathBuf,
+}
+
+impl Component for BookmarkRow {
+    fn render(&self, w: &mut impl Write) {
+        let line = format!("{}  {}", self.label, self.path.display());
+        w.write(line.as_bytes()).unwrap();
+    }
+}
+
+const PREVIEW_LINES: usize = 256;
+// A single line with no early newline (a minified asset, a binary-ish file) would
+// otherwise force `BufReader::lines()` to read all the way to the next `\n`/EOF before
+// `take(PREVIEW_LINES)` can stop it; cap the raw byte stream too so that can't stall.
+const PREVIEW_BYTES: u64 = 1024 * 1024;
+
 struct Context {
     offset: usize,
     pivot: Option<usize>,
     max_visible_rows: usize,
+    marked: BTreeSet<usize>,
+    // Absolute indices into `components` that pass the active `filter` command, in order.
+    // `None` means every entry is visible.
+    filter: Option<Vec<usize>>,
     // dimens: Rect,
 }
 
+// What the right-hand Miller-column shows for the currently selected entry.
+enum Preview {
+    None,
+    Dir(Vec<String>),
+    File(Vec<String>),
+    Highlighted(Vec<Vec<StyledSpan>>),
+    Image(Vec<Vec<ImageCell>>),
+}
+
+// A run of same-colored text within a highlighted preview line.
+struct StyledSpan {
+    fg: (u8, u8, u8),
+    text: String,
+}
+
+// One terminal cell of a downsampled image preview, drawn as an upper-half-block glyph
+// whose foreground/background approximate the pixel pair it stands in for.
+struct ImageCell {
+    fg: (u8, u8, u8),
+    bg: (u8, u8, u8),
+}
+
 trait Component {
     fn render(&self, w: &mut impl Write);
+
+    // Whether this entry's label matches a lowercase query; used by search/filter.
+    fn matches(&self, _query: &str) -> bool {
+        false
+    }
 }
 
 struct ListEntry {
@@ -327,6 +968,14 @@ impl Component for ListEntry {
         };
         w.write(target).unwrap();
     }
+
+    fn matches(&self, query: &str) -> bool {
+        self.dir_entry
+            .file_name()
+            .and_then(|n| n.to_str())
+            .map(|n| n.to_lowercase().contains(query))
+            .unwrap_or(false)
+    }
 }
 
 struct ListRenderer<'a, 'lock> {
@@ -354,6 +1003,7 @@ impl<'a, 'lock> ListRenderer<'a, 'lock> {
 struct ListWriter<'a, 'lock> {
     lock: &'a mut StdoutLock<'lock>,
     is_selected: bool,
+    is_marked: bool,
     line: usize,
     max_len: usize,
 }
@@ -364,6 +1014,7 @@ impl<'a, 'lock> ListWriter<'a, 'lock> {
             lock,
             max_len,
             is_selected: false,
+            is_marked: false,
             line: 0,
         }
     }
@@ -393,11 +1044,28 @@ impl<'a, 'lock> ListWriter<'a, 'lock> {
     fn unselect(&mut self) {
         self.is_selected = false;
     }
+
+    fn is_marked(&self) -> bool {
+        self.is_marked
+    }
+
+    fn set_marked(&mut self, marked: bool) {
+        self.is_marked = marked;
+    }
+
+    fn unmark(&mut self) {
+        self.is_marked = false;
+    }
 }
 
 impl Write for ListWriter<'_, '_> {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        let prefix = if self.is_selected() { b"> " } else { b"  " };
+        let prefix: &[u8] = match (self.is_selected(), self.is_marked()) {
+            (true, true) => b"*>",
+            (true, false) => b"> ",
+            (false, true) => b"* ",
+            (false, false) => b"  ",
+        };
         let mut target = prefix.to_vec();
         target.extend_from_slice(buf);
         let res = self.lock.write(&target[..min(target.len(), self.max_len)]);
@@ -427,9 +1095,9 @@ impl Drop for SelectionGuard<'_, '_> {
 }
 
 impl Renderer for ListRenderer<'_, '_> {
-    fn render<'a, I, T>(&mut self, components: I)
+    fn render<'a, I, T>(&mut self, components: I, preview: &Preview)
     where
-        I: Iterator<Item = (bool, &'a T)>,
+        I: Iterator<Item = (bool, bool, &'a T)>,
         T: Component + 'a,
     {
         self.stdout().queue(Clear(ClearType::All)).unwrap();
@@ -439,12 +1107,82 @@ impl Renderer for ListRenderer<'_, '_> {
             .queue(MoveTo(0, 0))
             .unwrap();
 
-        for (selected, c) in components {
+        let (list_bounds, preview_bounds) = self.bounds.split_cols();
+        self.writer.max_len = list_bounds.w;
+
+        for (selected, marked, c) in components {
             self.writer.set_selection(selected);
+            self.writer.set_marked(marked);
 
             c.render(&mut self.writer);
 
             self.writer.unselect();
+            self.writer.unmark();
+        }
+
+        match preview {
+            Preview::None => {}
+            Preview::Dir(lines) | Preview::File(lines) => {
+                for (row, line) in lines.iter().take(preview_bounds.h).enumerate() {
+                    let bytes = line.as_bytes();
+                    let truncated = &bytes[..min(bytes.len(), preview_bounds.w)];
+
+                    self.stdout()
+                        .queue(MoveTo(preview_bounds.x as u16, row as u16))
+                        .unwrap()
+                        .write_all(truncated)
+                        .unwrap();
+                }
+            }
+            Preview::Highlighted(lines) => {
+                for (row, spans) in lines.iter().take(preview_bounds.h).enumerate() {
+                    self.stdout()
+                        .queue(MoveTo(preview_bounds.x as u16, row as u16))
+                        .unwrap();
+
+                    let mut remaining = preview_bounds.w;
+
+                    for span in spans {
+                        if remaining == 0 {
+                            break;
+                        }
+
+                        let text: String = span.text.chars().take(remaining).collect();
+                        remaining -= text.chars().count();
+
+                        let (r, g, b) = span.fg;
+                        self.stdout()
+                            .queue(SetForegroundColor(Color::Rgb { r, g, b }))
+                            .unwrap()
+                            .write_all(text.as_bytes())
+                            .unwrap();
+                    }
+
+                    self.stdout().queue(ResetColor).unwrap();
+                }
+            }
+            Preview::Image(rows) => {
+                for (row, cells) in rows.iter().take(preview_bounds.h).enumerate() {
+                    self.stdout()
+                        .queue(MoveTo(preview_bounds.x as u16, row as u16))
+                        .unwrap();
+
+                    for cell in cells.iter().take(preview_bounds.w) {
+                        let (fr, fg, fb) = cell.fg;
+                        let (br, bg, bb) = cell.bg;
+
+                        self.stdout()
+                            .queue(SetForegroundColor(Color::Rgb { r: fr, g: fg, b: fb }))
+                            .unwrap()
+                            .queue(SetBackgroundColor(Color::Rgb { r: br, g: bg, b: bb }))
+                            .unwrap()
+                            .write_all("▀".as_bytes())
+                            .unwrap();
+                    }
+
+                    self.stdout().queue(ResetColor).unwrap();
+                }
+            }
         }
 
         self.stdout()
@@ -456,9 +1194,9 @@ impl Renderer for ListRenderer<'_, '_> {
 }
 
 trait Renderer {
-    fn render<'a, I, T>(&mut self, components: I)
+    fn render<'a, I, T>(&mut self, components: I, preview: &Preview)
     where
-        I: Iterator<Item = (bool, &'a T)>,
+        I: Iterator<Item = (bool, bool, &'a T)>,
         T: Component + 'a;
 }
 
@@ -484,6 +1222,8 @@ impl<C: Component> Rover<C> {
                 offset: 0,
                 pivot: None,
                 max_visible_rows: height,
+                marked: BTreeSet::new(),
+                filter: None,
                 // dimens,
             },
             // r: Some(r),
@@ -492,24 +1232,154 @@ impl<C: Component> Rover<C> {
 
     pub fn reset(&mut self, new_components: Vec<C>) {
         self.components = Some(new_components);
+        self.ctx.marked.clear();
+        self.ctx.filter = None;
+    }
+
+    // Absolute component indices currently visible, in display order: every index when
+    // there's no active filter, otherwise just the ones the filter matched.
+    fn visible_indices(&self) -> Box<dyn Iterator<Item = usize> + '_> {
+        match &self.ctx.filter {
+            Some(idxs) => Box::new(idxs.iter().copied()),
+            None => Box::new(0..self.len()),
+        }
     }
 
-    fn render(&mut self, r: &mut impl Renderer) {
+    // Narrows the visible set to entries whose label contains `query` (case-insensitive),
+    // or clears the filter entirely when `query` is blank.
+    fn set_filter(&mut self, query: &str) {
+        let query = query.trim();
+
+        if query.is_empty() {
+            self.ctx.filter = None;
+        } else {
+            let query = query.to_lowercase();
+            let idxs = self
+                .components
+                .as_ref()
+                .map(|c| {
+                    c.iter()
+                        .enumerate()
+                        .filter(|(_, e)| e.matches(&query))
+                        .map(|(i, _)| i)
+                        .collect()
+                })
+                .unwrap_or_default();
+            self.ctx.filter = Some(idxs);
+        }
+
+        if !self.visible_indices().any(|i| Some(i) == self.ctx.pivot) {
+            let first_visible = self.visible_indices().next();
+            self.ctx.pivot = first_visible;
+        }
+
+        self.ctx.offset = 0;
+        self.sync_offset();
+    }
+
+    // Moves the pivot to the next (or previous, for `Direction::Up`) visible entry whose
+    // label contains `query`, wrapping around like `shift`. A no-op if nothing matches.
+    fn jump_to_match(&mut self, query: &str, dir: Direction) {
+        if query.is_empty() {
+            return;
+        }
+
+        let visible: Vec<usize> = self.visible_indices().collect();
+        let Some(components) = self.components.as_ref() else {
+            return;
+        };
+
+        if visible.is_empty() {
+            return;
+        }
+
+        let len = visible.len();
+        let cur_pos = self
+            .ctx
+            .pivot
+            .and_then(|p| visible.iter().position(|&i| i == p))
+            .unwrap_or(0);
+
+        for step in 1..=len {
+            let pos = match dir {
+                Direction::Down => (cur_pos + step) % len,
+                Direction::Up => (cur_pos + len - step % len) % len,
+            };
+            let abs = visible[pos];
+
+            if components[abs].matches(query) {
+                self.ctx.pivot = Some(abs);
+                self.sync_offset();
+                return;
+            }
+        }
+    }
+
+    fn render(&mut self, r: &mut impl Renderer, preview: &Preview) {
         let offset = self.ctx.offset;
         let max_rows = self.ctx.max_visible_rows;
         let pivot = self.ctx.pivot;
+        let marked = &self.ctx.marked;
+        let components = self.components.as_ref().unwrap();
 
-        let components = self
-            .components
-            .as_ref()
-            .unwrap()
-            .iter()
+        let visible: Vec<usize> = self.visible_indices().collect();
+
+        let rows = visible
+            .into_iter()
             .skip(offset)
             .take(max_rows)
-            .enumerate()
-            .map(|(idx, c)| (pivot.map(|p| p == idx).unwrap_or_default(), c));
+            .map(|abs| {
+                (
+                    pivot.map(|p| p == abs).unwrap_or_default(),
+                    marked.contains(&abs),
+                    &components[abs],
+                )
+            });
+
+        r.render(rows, preview);
+    }
 
-        r.render(components);
+    fn toggle_mark(&mut self) {
+        let Some(pivot) = self.ctx.pivot else {
+            return;
+        };
+
+        if !self.ctx.marked.remove(&pivot) {
+            self.ctx.marked.insert(pivot);
+        }
+    }
+
+    // Inverts marks only across what's currently on screen, so an active filter doesn't
+    // let "mark all" quietly reach past it and mark entries the user can't even see.
+    fn invert_marks(&mut self) {
+        let visible: Vec<usize> = self.visible_indices().collect();
+
+        for i in visible {
+            if !self.ctx.marked.remove(&i) {
+                self.ctx.marked.insert(i);
+            }
+        }
+    }
+
+    fn clear_marks(&mut self) {
+        self.ctx.marked.clear();
+    }
+
+    fn marked_or_selected(&self) -> Vec<&C> {
+        if self.ctx.marked.is_empty() {
+            return self.selected_ref().into_iter().collect();
+        }
+
+        let components = match self.components.as_ref() {
+            Some(c) => c,
+            None => return vec![],
+        };
+
+        self.ctx
+            .marked
+            .iter()
+            .filter_map(|&i| components.get(i))
+            .collect()
     }
 
     // fn update_selection(&mut self) {
@@ -530,34 +1400,113 @@ impl<C: Component> Rover<C> {
         let range = 0..self.len();
         assert!(range.contains(&idx), "{}", format!("idx: {} range: {:?}", idx, range));
         self.ctx.pivot = Some(idx);
+        self.sync_offset();
     }
 
-    fn resize(&mut self, w: usize, h: usize) {
-        // TODO: update height + ctx
-        todo!();
+    // Keeps the selected pivot within the visible window, scrolling the offset (which
+    // counts positions in the visible/filtered list, not raw component indices) up or
+    // down just enough to bring the pivot's row back on screen.
+    fn sync_offset(&mut self) {
+        let Some(pivot) = self.ctx.pivot else {
+            return;
+        };
+
+        let visible: Vec<usize> = self.visible_indices().collect();
+        let pos = visible.iter().position(|&i| i == pivot).unwrap_or(0);
+
+        if pos < self.ctx.offset {
+            self.ctx.offset = pos;
+        } else if pos >= self.ctx.offset + self.ctx.max_visible_rows {
+            self.ctx.offset = pos + 1 - self.ctx.max_visible_rows;
+        }
+
+        // Clamp back down so a grown window (e.g. a terminal resize) doesn't leave the
+        // list scrolled past the point where it'd fill the extra rows.
+        self.ctx.offset = self
+            .ctx
+            .offset
+            .min(visible.len().saturating_sub(self.ctx.max_visible_rows));
+    }
+
+    // `w` isn't tracked by `Rover` (only the list's row count matters here); the
+    // terminal's width just flows through `Rect`/`ListRenderer` directly.
+    fn resize(&mut self, _w: usize, h: usize) {
+        self.ctx.max_visible_rows = h;
+        self.sync_offset();
     }
 
     fn shift(&mut self, d: Direction) {
-        let len = self.len();
-        let pivot = self.ctx.pivot.as_mut().unwrap();
+        let visible: Vec<usize> = self.visible_indices().collect();
+        if visible.is_empty() {
+            return;
+        }
+
+        let len = visible.len();
+        let cur_pos = self
+            .ctx
+            .pivot
+            .and_then(|p| visible.iter().position(|&i| i == p))
+            .unwrap_or(0);
 
-        match d {
+        let next_pos = match d {
             Direction::Up => {
-                if *pivot as i64 - 1 < 0 {
-                    *pivot = len - 1;
-                } else {
-                    *pivot -= 1;
-                }
-            }
-            Direction::Down => {
-                *pivot += 1;
-                *pivot %= len;
+                if cur_pos == 0 { len - 1 } else { cur_pos - 1 }
             }
+            Direction::Down => (cur_pos + 1) % len,
         };
 
+        self.ctx.pivot = Some(visible[next_pos]);
+        self.sync_offset();
+
         // self.update_selection();
     }
 
+    // Moves the pivot by a full page (`max_visible_rows` rows), clamping at either end
+    // instead of wrapping like `shift`.
+    fn page(&mut self, d: Direction) {
+        let visible: Vec<usize> = self.visible_indices().collect();
+        if visible.is_empty() {
+            return;
+        }
+
+        let len = visible.len();
+        let cur_pos = self
+            .ctx
+            .pivot
+            .and_then(|p| visible.iter().position(|&i| i == p))
+            .unwrap_or(0);
+
+        let page = self.ctx.max_visible_rows.max(1);
+
+        let next_pos = match d {
+            Direction::Up => cur_pos.saturating_sub(page),
+            Direction::Down => (cur_pos + page).min(len - 1),
+        };
+
+        self.ctx.pivot = Some(visible[next_pos]);
+        self.sync_offset();
+    }
+
+    // Jumps the pivot to the first visible entry.
+    fn jump_to_start(&mut self) {
+        let first = self.visible_indices().next();
+
+        if let Some(first) = first {
+            self.ctx.pivot = Some(first);
+            self.sync_offset();
+        }
+    }
+
+    // Jumps the pivot to the last visible entry.
+    fn jump_to_end(&mut self) {
+        let last = self.visible_indices().last();
+
+        if let Some(last) = last {
+            self.ctx.pivot = Some(last);
+            self.sync_offset();
+        }
+    }
+
     pub fn len(&self) -> usize {
         self.components
             .as_ref()
@@ -566,11 +1515,17 @@ impl<C: Component> Rover<C> {
     }
 }
 
-#[derive(Default, Debug)]
+#[derive(Default, Debug, PartialEq, Eq)]
 enum Mode {
     #[default]
     Flow,
     Command,
+    // Next keypress labels `current_path` as a bookmark.
+    BookmarkSet,
+    // Next keypress jumps to the bookmark under that label; the popup shows all of them.
+    BookmarkJump,
+    // Awaiting a 'y' to confirm trashing the marked (or selected) entries.
+    ConfirmDelete,
 }
 
 impl fmt::Display for Mode {